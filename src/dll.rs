@@ -0,0 +1,196 @@
+use crate::ffi;
+use libloading::{Library, Symbol};
+use std::{
+    os::raw::{c_int, c_void},
+    ptr::null_mut,
+    time::Duration,
+};
+
+type UpdateFieldInfoFn = extern "C" fn(*mut ffi::FieldInfo) -> ffi::RLBotCoreStatus;
+type UpdateLiveDataPacketFn = extern "C" fn(*mut ffi::LiveDataPacket) -> ffi::RLBotCoreStatus;
+type UpdateRigidBodyTickFn = extern "C" fn(*mut ffi::RigidBodyTick) -> ffi::RLBotCoreStatus;
+type SetGameStateFn = extern "C" fn(*mut c_void, c_int) -> ffi::RLBotCoreStatus;
+type StartMatchFn = extern "C" fn(
+    ffi::MatchSettings,
+    Option<unsafe extern "C" fn()>,
+    *mut c_void,
+) -> ffi::RLBotCoreStatus;
+type StartMatchFlatbufferFn = extern "C" fn(*mut c_void, c_int) -> ffi::RLBotCoreStatus;
+type SendQuickChatFn = extern "C" fn(*mut c_void, c_int) -> ffi::RLBotCoreStatus;
+type UpdatePlayerInputFn = extern "C" fn(ffi::PlayerInput, c_int) -> ffi::RLBotCoreStatus;
+type UpdatePlayerInputFlatbufferFn = extern "C" fn(*mut c_void, c_int) -> ffi::RLBotCoreStatus;
+type RenderGroupFn = extern "C" fn(*mut c_void, c_int) -> ffi::RLBotCoreStatus;
+type GetBallPredictionStructFn =
+    extern "C" fn(*mut ffi::BallPredictionPacket) -> ffi::RLBotCoreStatus;
+
+/// Every `*Flatbuffer` getter follows the same one-call ABI: the DLL writes
+/// the buffer's size and a pointer it owns into the two out-params and
+/// returns them both in a single call; the caller copies the bytes out and
+/// then must free the pointer via [`FreeFlatbufferFn`].
+type GetFlatbufferFn = unsafe extern "C" fn(*mut c_int, *mut *mut c_void) -> ffi::RLBotCoreStatus;
+type FreeFlatbufferFn = unsafe extern "C" fn(*mut c_void);
+
+/// Blocking "fresh packet" entry points. `timeout_millis` bounds how long the
+/// call may block; the DLL returns as soon as a frame newer than the
+/// caller's last one is published, or a timeout status if none arrives in
+/// time.
+type FreshFlatbufferFn =
+    unsafe extern "C" fn(c_int, *mut c_int, *mut *mut c_void) -> ffi::RLBotCoreStatus;
+
+/// Thin, safe-ish wrapper over the RLBot core DLL's C ABI, loaded once via
+/// `libloading` and kept alive for the lifetime of the bound function
+/// pointers.
+pub struct RLBotCoreInterface {
+    _lib: Library,
+
+    pub(crate) update_field_info: UpdateFieldInfoFn,
+    update_field_info_flatbuffer: GetFlatbufferFn,
+    pub(crate) update_live_data_packet: UpdateLiveDataPacketFn,
+    update_live_data_packet_flatbuffer: GetFlatbufferFn,
+    pub(crate) update_rigid_body_tick: UpdateRigidBodyTickFn,
+    update_rigid_body_tick_flatbuffer: GetFlatbufferFn,
+    pub(crate) set_game_state: SetGameStateFn,
+    pub(crate) start_match: StartMatchFn,
+    pub(crate) start_match_flatbuffer: StartMatchFlatbufferFn,
+    pub(crate) send_quick_chat: SendQuickChatFn,
+    pub(crate) update_player_input: UpdatePlayerInputFn,
+    pub(crate) update_player_input_flatbuffer: UpdatePlayerInputFlatbufferFn,
+    pub(crate) render_group: RenderGroupFn,
+    get_ball_prediction: GetFlatbufferFn,
+    pub(crate) get_ball_prediction_struct: GetBallPredictionStructFn,
+    free_flatbuffer: FreeFlatbufferFn,
+
+    // Not every RLBot core DLL build exports these; older builds only
+    // support the polling `Update*` family, so these are probed at load
+    // time and left `None` when the symbol can't be found.
+    fresh_rigid_body_tick: Option<FreshFlatbufferFn>,
+    fresh_live_data_packet: Option<FreshFlatbufferFn>,
+}
+
+impl RLBotCoreInterface {
+    pub(crate) fn load(lib: Library) -> Result<Self, libloading::Error> {
+        macro_rules! required {
+            ($name:literal) => {
+                *unsafe { lib.get::<_>($name) }?
+            };
+        }
+
+        let update_field_info = required!(b"UpdateFieldInfo");
+        let update_field_info_flatbuffer = required!(b"UpdateFieldInfoFlatbuffer");
+        let update_live_data_packet = required!(b"UpdateLiveDataPacket");
+        let update_live_data_packet_flatbuffer = required!(b"UpdateLiveDataPacketFlatbuffer");
+        let update_rigid_body_tick = required!(b"UpdateRigidBodyTick");
+        let update_rigid_body_tick_flatbuffer = required!(b"UpdateRigidBodyTickFlatbuffer");
+        let set_game_state = required!(b"SetGameState");
+        let start_match = required!(b"StartMatch");
+        let start_match_flatbuffer = required!(b"StartMatchFlatbuffer");
+        let send_quick_chat = required!(b"SendQuickChat");
+        let update_player_input = required!(b"UpdatePlayerInput");
+        let update_player_input_flatbuffer = required!(b"UpdatePlayerInputFlatbuffer");
+        let render_group = required!(b"RenderGroup");
+        let get_ball_prediction = required!(b"GetBallPrediction");
+        let get_ball_prediction_struct = required!(b"GetBallPredictionStruct");
+        let free_flatbuffer = required!(b"FreeFlatbuffer");
+
+        // Blocking entry points are optional: fall back to `None` rather
+        // than failing to load when an older DLL doesn't export them.
+        let fresh_rigid_body_tick = unsafe {
+            lib.get::<FreshFlatbufferFn>(b"FreshRigidBodyTick")
+                .ok()
+                .map(|sym: Symbol<'_, FreshFlatbufferFn>| *sym)
+        };
+        let fresh_live_data_packet = unsafe {
+            lib.get::<FreshFlatbufferFn>(b"FreshLiveDataPacket")
+                .ok()
+                .map(|sym: Symbol<'_, FreshFlatbufferFn>| *sym)
+        };
+
+        Ok(Self {
+            _lib: lib,
+            update_field_info,
+            update_field_info_flatbuffer,
+            update_live_data_packet,
+            update_live_data_packet_flatbuffer,
+            update_rigid_body_tick,
+            update_rigid_body_tick_flatbuffer,
+            set_game_state,
+            start_match,
+            start_match_flatbuffer,
+            send_quick_chat,
+            update_player_input,
+            update_player_input_flatbuffer,
+            render_group,
+            get_ball_prediction,
+            get_ball_prediction_struct,
+            free_flatbuffer,
+            fresh_rigid_body_tick,
+            fresh_live_data_packet,
+        })
+    }
+
+    fn call_flatbuffer(&self, f: GetFlatbufferFn) -> Option<Vec<u8>> {
+        let mut size: c_int = 0;
+        let mut ptr: *mut c_void = null_mut();
+        let status = unsafe { f(&mut size, &mut ptr) };
+        self.take_flatbuffer(status, size, ptr)
+    }
+
+    fn take_flatbuffer(&self, status: ffi::RLBotCoreStatus, size: c_int, ptr: *mut c_void) -> Option<Vec<u8>> {
+        if status != ffi::RLBotCoreStatus::Success || ptr.is_null() {
+            return None;
+        }
+        let buf = unsafe { std::slice::from_raw_parts(ptr as *const u8, size as usize) }.to_vec();
+        unsafe { (self.free_flatbuffer)(ptr) };
+        Some(buf)
+    }
+
+    pub fn update_field_info_flatbuffer(&self) -> Option<Vec<u8>> {
+        self.call_flatbuffer(self.update_field_info_flatbuffer)
+    }
+
+    pub fn update_live_data_packet_flatbuffer(&self) -> Option<Vec<u8>> {
+        self.call_flatbuffer(self.update_live_data_packet_flatbuffer)
+    }
+
+    pub fn update_rigid_body_tick_flatbuffer(&self) -> Option<Vec<u8>> {
+        self.call_flatbuffer(self.update_rigid_body_tick_flatbuffer)
+    }
+
+    pub fn get_ball_prediction(&self) -> Option<Vec<u8>> {
+        self.call_flatbuffer(self.get_ball_prediction)
+    }
+
+    /// Returns `true` if the connected DLL exports the blocking fresh-packet
+    /// entry points (`FreshLiveDataPacket`/`FreshRigidBodyTick`). Older
+    /// builds of the RLBot core DLL only export the polling `Update*`
+    /// family.
+    pub fn has_fresh_packet_support(&self) -> bool {
+        self.fresh_rigid_body_tick.is_some() && self.fresh_live_data_packet.is_some()
+    }
+
+    /// Blocks (up to `timeout`) until the DLL publishes a physics tick newer
+    /// than the caller's last one, then returns its FlatBuffer bytes.
+    ///
+    /// Returns `None` on timeout, or if the connected DLL doesn't export the
+    /// symbol (see [`has_fresh_packet_support`](Self::has_fresh_packet_support)).
+    pub fn fresh_rigid_body_tick_flatbuffer(&self, timeout: Duration) -> Option<Vec<u8>> {
+        self.call_fresh_flatbuffer(self.fresh_rigid_body_tick?, timeout)
+    }
+
+    /// Blocks (up to `timeout`) until the DLL publishes a game packet newer
+    /// than the caller's last one, then returns its FlatBuffer bytes.
+    ///
+    /// Returns `None` on timeout, or if the connected DLL doesn't export the
+    /// symbol (see [`has_fresh_packet_support`](Self::has_fresh_packet_support)).
+    pub fn fresh_live_data_packet_flatbuffer(&self, timeout: Duration) -> Option<Vec<u8>> {
+        self.call_fresh_flatbuffer(self.fresh_live_data_packet?, timeout)
+    }
+
+    fn call_fresh_flatbuffer(&self, f: FreshFlatbufferFn, timeout: Duration) -> Option<Vec<u8>> {
+        let timeout_millis = timeout.as_millis().min(c_int::MAX as u128) as c_int;
+        let mut size: c_int = 0;
+        let mut ptr: *mut c_void = null_mut();
+        let status = unsafe { f(timeout_millis, &mut size, &mut ptr) };
+        self.take_flatbuffer(status, size, ptr)
+    }
+}
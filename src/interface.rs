@@ -2,6 +2,7 @@ use crate::{dll::RLBotCoreInterface, error::RLBotError, ffi, flat, game::*};
 use std::{
     os::raw::{c_int, c_void},
     ptr::null_mut,
+    time::Duration,
 };
 
 pub struct RLBotInterface {
@@ -59,6 +60,40 @@ impl RLBotInterface {
             .map(|buf| flatbuffers::get_root::<flat::RigidBodyTick<'_>>(&buf).into())
     }
 
+    /// Returns `true` if the connected RLBot core DLL exports the blocking
+    /// "fresh packet" entry points used by
+    /// [`fresh_rigid_body_tick_flatbuffer`](Self::fresh_rigid_body_tick_flatbuffer)
+    /// and [`fresh_live_data_packet_flatbuffer`](Self::fresh_live_data_packet_flatbuffer).
+    /// Older DLLs only support the polling `update_*` family, in which case
+    /// callers should fall back to polling.
+    pub fn supports_fresh_packets(&self) -> bool {
+        self.dll.has_fresh_packet_support()
+    }
+
+    /// Blocks until the RLBot core DLL publishes a physics tick, then grabs
+    /// it as a FlatBuffer table.
+    ///
+    /// Returns `None` if `timeout` elapses before a new tick is published, or
+    /// if the connected DLL does not export the blocking entry point (see
+    /// [`supports_fresh_packets`](Self::supports_fresh_packets)).
+    pub fn fresh_rigid_body_tick_flatbuffer(&self, timeout: Duration) -> Option<RigidBodyTick> {
+        self.dll
+            .fresh_rigid_body_tick_flatbuffer(timeout)
+            .map(|buf| flatbuffers::get_root::<flat::RigidBodyTick<'_>>(&buf).into())
+    }
+
+    /// Blocks until the RLBot core DLL publishes a game packet, then grabs it
+    /// as a FlatBuffer table.
+    ///
+    /// Returns `None` if `timeout` elapses before a new packet is published,
+    /// or if the connected DLL does not export the blocking entry point (see
+    /// [`supports_fresh_packets`](Self::supports_fresh_packets)).
+    pub fn fresh_live_data_packet_flatbuffer(&self, timeout: Duration) -> Option<GameTickPacket> {
+        self.dll
+            .fresh_live_data_packet_flatbuffer(timeout)
+            .map(|buf| flatbuffers::get_root::<flat::GameTickPacket<'_>>(&buf).into())
+    }
+
     /// Grabs the current physics tick as a struct.
     #[deprecated(
         note = "the struct-based methods are deprecated; use the flatbuffer equivalents instead"
@@ -153,6 +188,16 @@ impl RLBotInterface {
             .map(|buf| flatbuffers::get_root::<flat::BallPrediction<'_>>(&buf).into())
     }
 
+    /// Gets the framework's current prediction of ball motion, wrapped in a
+    /// [`PredictedTrajectory`] that can be queried at an arbitrary future
+    /// time instead of scanning the raw slices by hand.
+    ///
+    /// Note that this method requires the framework's `BallPrediction.exe` to
+    /// be running in the background.
+    pub fn get_predicted_trajectory(&self) -> Option<PredictedTrajectory> {
+        self.get_ball_prediction().map(PredictedTrajectory::new)
+    }
+
     /// Gets the framework's current prediction of ball motion as a struct.
     ///
     /// Note that this method requires the framework's `BallPrediction.exe` to
@@ -176,8 +221,135 @@ fn core_result(status: ffi::RLBotCoreStatus) -> Result<(), RLBotError> {
     }
 }
 
+/// A queryable wrapper over the slices returned by
+/// [`get_predicted_trajectory`](RLBotInterface::get_predicted_trajectory).
+///
+/// The raw `BallPrediction` is a flat buffer of timestamped samples; this
+/// turns it into a curve that can be sampled at any time within the horizon.
+pub struct PredictedTrajectory {
+    slices: Vec<PredictionSlice>,
+}
+
+impl PredictedTrajectory {
+    fn new(prediction: BallPrediction) -> Self {
+        Self {
+            slices: prediction.slices,
+        }
+    }
+
+    /// Returns the predicted ball physics at time `t` (in the same
+    /// `game_seconds` units as the slices), linearly interpolating
+    /// position/velocity and slerping rotation between the two bracketing
+    /// slices.
+    ///
+    /// Returns `None` if `t` falls outside the predicted horizon.
+    pub fn state_at(&self, t: f32) -> Option<Physics> {
+        let first = self.slices.first()?.game_seconds;
+        let i = self
+            .slices
+            .partition_point(|slice| slice.game_seconds < t)
+            .max(1);
+
+        if t.is_nan() || t < first || i >= self.slices.len() {
+            return None;
+        }
+
+        let a = &self.slices[i - 1];
+        let b = &self.slices[i];
+        let span = b.game_seconds - a.game_seconds;
+        let frac = if span > 0.0 {
+            (t - a.game_seconds) / span
+        } else {
+            0.0
+        };
+
+        Some(crate::interp::lerp_physics(&a.physics, &b.physics, frac))
+    }
+
+    /// Scans the predicted slices for the first time the ball's height drops
+    /// to or below `ground_z` (the ball's resting height), returning the
+    /// `game_seconds` at which that happens.
+    pub fn time_to_ground(&self, ground_z: f32) -> Option<f32> {
+        self.slices
+            .iter()
+            .find(|slice| slice.physics.location.z <= ground_z)
+            .map(|slice| slice.game_seconds)
+    }
+
+    /// Scans the predicted slices for bounces: local minima in height where
+    /// the ball's vertical velocity flips from falling to rising near
+    /// `ground_z`. Returns the `game_seconds` of each detected bounce.
+    pub fn bounces(&self, ground_z: f32) -> Vec<f32> {
+        self.slices
+            .windows(2)
+            .filter(|pair| {
+                let (prev, next) = (&pair[0], &pair[1]);
+                prev.physics.location.z <= ground_z
+                    && prev.physics.velocity.z < 0.0
+                    && next.physics.velocity.z > 0.0
+            })
+            .map(|pair| pair[0].game_seconds)
+            .collect()
+    }
+}
+
+/// Tracks how far a previous [`PredictedTrajectory`] has diverged from the
+/// ground truth as real ticks arrive, so a bot can gauge how much to trust
+/// the curve rather than treating every prediction as exact.
+pub struct PredictionTracker {
+    last_trajectory: Option<PredictedTrajectory>,
+    correction_magnitude: f32,
+}
+
+impl PredictionTracker {
+    pub fn new() -> Self {
+        Self {
+            last_trajectory: None,
+            correction_magnitude: 0.0,
+        }
+    }
+
+    /// Records a freshly fetched prediction to compare future ground truth
+    /// against.
+    pub fn observe_prediction(&mut self, trajectory: PredictedTrajectory) {
+        self.last_trajectory = Some(trajectory);
+    }
+
+    /// Compares a just-arrived ground-truth ball position at `game_seconds`
+    /// against what the previously observed prediction said for that time,
+    /// folding the position error into a running correction magnitude (an
+    /// exponential moving average, so recent error dominates).
+    pub fn observe_ground_truth(&mut self, game_seconds: f32, location: Vector3) {
+        const SMOOTHING: f32 = 0.1;
+
+        if let Some(predicted) = self
+            .last_trajectory
+            .as_ref()
+            .and_then(|t| t.state_at(game_seconds))
+        {
+            let error = crate::interp::distance(&predicted.location, &location);
+            self.correction_magnitude =
+                self.correction_magnitude * (1.0 - SMOOTHING) + error * SMOOTHING;
+        }
+    }
+
+    /// The current running correction magnitude: how far, on average, recent
+    /// predictions have missed the ground truth. Larger values mean the
+    /// curve should be trusted less.
+    pub fn correction_magnitude(&self) -> f32 {
+        self.correction_magnitude
+    }
+}
+
+impl Default for PredictionTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{ffi, interface::RLBotInterface};
     use std::{error::Error, mem::MaybeUninit};
 
@@ -199,4 +371,71 @@ mod tests {
         assert_send(interface.get_ball_prediction());
         Ok(())
     }
+
+    fn physics_at(x: f32) -> Physics {
+        Physics {
+            location: Vector3 { x, y: 0.0, z: 0.0 },
+            velocity: Vector3 { x: 1.0, y: 0.0, z: 0.0 },
+            angular_velocity: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            rotation: Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 },
+        }
+    }
+
+    fn slice_at(game_seconds: f32, location_x: f32) -> PredictionSlice {
+        PredictionSlice {
+            game_seconds,
+            physics: physics_at(location_x),
+        }
+    }
+
+    fn trajectory(slices: Vec<PredictionSlice>) -> super::PredictedTrajectory {
+        super::PredictedTrajectory::new(BallPrediction { slices })
+    }
+
+    #[test]
+    fn state_at_interpolates_between_bracketing_slices() {
+        let traj = trajectory(vec![slice_at(0.0, 0.0), slice_at(1.0, 10.0)]);
+        let state = traj.state_at(0.5).expect("within horizon");
+        assert!((state.location.x - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn state_at_returns_none_outside_the_horizon() {
+        let traj = trajectory(vec![slice_at(0.0, 0.0), slice_at(1.0, 10.0)]);
+        assert!(traj.state_at(-0.1).is_none());
+        assert!(traj.state_at(1.1).is_none());
+    }
+
+    #[test]
+    fn time_to_ground_finds_the_first_slice_at_or_below_ground() {
+        let mut high = slice_at(0.0, 0.0);
+        high.physics.location.z = 100.0;
+        let mut low = slice_at(1.0, 10.0);
+        low.physics.location.z = 0.0;
+        let traj = trajectory(vec![high, low]);
+        assert_eq!(traj.time_to_ground(0.0), Some(1.0));
+    }
+
+    #[test]
+    fn bounces_detects_a_velocity_flip_near_the_ground() {
+        let mut falling = slice_at(0.0, 0.0);
+        falling.physics.location.z = 0.0;
+        falling.physics.velocity.z = -10.0;
+        let mut rising = slice_at(1.0 / 60.0, 0.0);
+        rising.physics.location.z = 0.0;
+        rising.physics.velocity.z = 10.0;
+        let traj = trajectory(vec![falling, rising]);
+        assert_eq!(traj.bounces(0.0), vec![0.0]);
+    }
+
+    #[test]
+    fn prediction_tracker_grows_correction_magnitude_with_divergent_ground_truth() {
+        let mut tracker = super::PredictionTracker::new();
+        assert_eq!(tracker.correction_magnitude(), 0.0);
+
+        tracker.observe_prediction(trajectory(vec![slice_at(0.0, 0.0), slice_at(1.0, 10.0)]));
+        tracker.observe_ground_truth(0.5, Vector3 { x: 50.0, y: 0.0, z: 0.0 });
+
+        assert!(tracker.correction_magnitude() > 0.0);
+    }
 }
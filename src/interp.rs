@@ -0,0 +1,142 @@
+//! Shared interpolation math used by the ball prediction trajectory
+//! ([`crate::interface::PredictedTrajectory`]) and the physics jitter buffer
+//! ([`crate::physicist`]): linear interpolation for position/velocity and
+//! spherical interpolation (slerp) for rotation.
+
+use crate::game::{Physics, Quaternion, Vector3};
+
+/// Lerps location/velocity/angular velocity and slerps rotation between two
+/// physics states, returning the interpolated result at normalized time `t`
+/// in `[0, 1]`.
+pub(crate) fn lerp_physics(a: &Physics, b: &Physics, t: f32) -> Physics {
+    Physics {
+        location: lerp_vec3(&a.location, &b.location, t),
+        velocity: lerp_vec3(&a.velocity, &b.velocity, t),
+        angular_velocity: lerp_vec3(&a.angular_velocity, &b.angular_velocity, t),
+        rotation: slerp_quat(&a.rotation, &b.rotation, t),
+    }
+}
+
+pub(crate) fn lerp_vec3(a: &Vector3, b: &Vector3, t: f32) -> Vector3 {
+    Vector3 {
+        x: a.x + (b.x - a.x) * t,
+        y: a.y + (b.y - a.y) * t,
+        z: a.z + (b.z - a.z) * t,
+    }
+}
+
+pub(crate) fn distance(a: &Vector3, b: &Vector3) -> f32 {
+    let (dx, dy, dz) = (a.x - b.x, a.y - b.y, a.z - b.z);
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+pub(crate) fn slerp_quat(a: &Quaternion, b: &Quaternion, t: f32) -> Quaternion {
+    let mut dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+
+    // Take the shorter path around the hypersphere.
+    let mut b = *b;
+    if dot < 0.0 {
+        b.x = -b.x;
+        b.y = -b.y;
+        b.z = -b.z;
+        b.w = -b.w;
+        dot = -dot;
+    }
+
+    // Samples this close together are nearly identical; avoid a divide by a
+    // near-zero sine by falling back to a plain lerp (re-normalized).
+    if dot > 0.9995 {
+        let lerped = Quaternion {
+            x: a.x + (b.x - a.x) * t,
+            y: a.y + (b.y - a.y) * t,
+            z: a.z + (b.z - a.z) * t,
+            w: a.w + (b.w - a.w) * t,
+        };
+        return normalize_quat(&lerped);
+    }
+
+    let theta_0 = dot.acos();
+    let theta = theta_0 * t;
+    let sin_theta_0 = theta_0.sin();
+    let s0 = (theta_0 - theta).sin() / sin_theta_0;
+    let s1 = theta.sin() / sin_theta_0;
+
+    Quaternion {
+        x: a.x * s0 + b.x * s1,
+        y: a.y * s0 + b.y * s1,
+        z: a.z * s0 + b.z * s1,
+        w: a.w * s0 + b.w * s1,
+    }
+}
+
+fn normalize_quat(q: &Quaternion) -> Quaternion {
+    let len = (q.x * q.x + q.y * q.y + q.z * q.z + q.w * q.w).sqrt();
+    Quaternion {
+        x: q.x / len,
+        y: q.y / len,
+        z: q.z / len,
+        w: q.w / len,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quat(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
+        Quaternion { x, y, z, w }
+    }
+
+    fn vec3(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3 { x, y, z }
+    }
+
+    #[test]
+    fn lerp_vec3_at_t_zero_returns_a() {
+        let a = vec3(1.0, 2.0, 3.0);
+        let b = vec3(10.0, 20.0, 30.0);
+        assert_eq!(lerp_vec3(&a, &b, 0.0), a);
+    }
+
+    #[test]
+    fn lerp_vec3_at_t_one_returns_b() {
+        let a = vec3(1.0, 2.0, 3.0);
+        let b = vec3(10.0, 20.0, 30.0);
+        assert_eq!(lerp_vec3(&a, &b, 1.0), b);
+    }
+
+    #[test]
+    fn lerp_vec3_at_t_half_returns_the_midpoint() {
+        let a = vec3(0.0, 0.0, 0.0);
+        let b = vec3(10.0, 20.0, 30.0);
+        assert_eq!(lerp_vec3(&a, &b, 0.5), vec3(5.0, 10.0, 15.0));
+    }
+
+    #[test]
+    fn slerp_quat_identity_endpoints() {
+        let a = quat(0.0, 0.0, 0.0, 1.0);
+        let b = quat(0.0, 0.7071068, 0.0, 0.7071068);
+        let at_zero = slerp_quat(&a, &b, 0.0);
+        let at_one = slerp_quat(&a, &b, 1.0);
+        assert!((at_zero.w - a.w).abs() < 1e-5);
+        assert!((at_one.w - b.w).abs() < 1e-5);
+    }
+
+    #[test]
+    fn slerp_quat_stays_normalized_partway() {
+        let a = quat(0.0, 0.0, 0.0, 1.0);
+        let b = quat(0.0, 0.7071068, 0.0, 0.7071068);
+        let mid = slerp_quat(&a, &b, 0.5);
+        let len = (mid.x * mid.x + mid.y * mid.y + mid.z * mid.z + mid.w * mid.w).sqrt();
+        assert!((len - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn slerp_quat_takes_the_shorter_path_when_dot_is_negative() {
+        let a = quat(0.0, 0.0, 0.0, 1.0);
+        let b = quat(0.0, 0.0, 0.0, -1.0); // antipodal representation of `a`
+        let mid = slerp_quat(&a, &b, 0.5);
+        let len = (mid.x * mid.x + mid.y * mid.y + mid.z * mid.z + mid.w * mid.w).sqrt();
+        assert!((len - 1.0).abs() < 1e-5);
+    }
+}
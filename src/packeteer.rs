@@ -0,0 +1,147 @@
+use crate::{ffi, game, rlbot::RLBot};
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
+
+/// An iterator-like object that yields game packets from the game as they
+/// occur.
+pub struct Packeteer<'a> {
+    rlbot: &'a RLBot,
+    ratelimiter: ratelimit::Limiter,
+    use_fresh_packets: bool,
+}
+
+impl<'a> Packeteer<'a> {
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub(crate) fn new(rlbot: &'a RLBot) -> Self {
+        // Game packets happen at 120Hz. The goal is never to miss any. But if we poll
+        // too often, the game crashes, so space out the checks.
+        let ratelimiter = ratelimit::Builder::new()
+            .interval(Duration::from_millis(1))
+            .build();
+
+        Self {
+            rlbot,
+            ratelimiter,
+            use_fresh_packets: rlbot.interface().supports_fresh_packets(),
+        }
+    }
+
+    /// Block until the next game packet occurs, and then return it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if ten seconds pass without a new
+    /// packet being received. The assumption is that the game froze or
+    /// crashed, and waiting longer will not help.
+    #[allow(clippy::should_implement_trait)]
+    #[deprecated(
+        note = "the struct-based methods are deprecated; use the flatbuffer equivalents instead"
+    )]
+    #[allow(deprecated)]
+    pub fn next(&mut self) -> Result<ffi::LiveDataPacket, Box<dyn Error>> {
+        self.spin(|this| Ok(this.try_next()?), Self::DEFAULT_TIMEOUT)
+    }
+
+    /// Polls for a new game packet.
+    ///
+    /// Like [`try_next_flat`](Self::try_next_flat), every successfully
+    /// retrieved packet is returned as `Some`; it is not compared against
+    /// the previous packet.
+    #[deprecated(
+        note = "the struct-based methods are deprecated; use the flatbuffer equivalents instead"
+    )]
+    #[allow(deprecated)]
+    pub fn try_next(&mut self) -> Result<Option<ffi::LiveDataPacket>, Box<dyn Error>> {
+        let mut result = ffi::LiveDataPacket::default();
+        self.rlbot.interface().update_live_data_packet(&mut result)?;
+        Ok(Some(result))
+    }
+
+    /// Block until the next game packet occurs, and then return it.
+    ///
+    /// If the connected RLBot core DLL exports the blocking fresh-packet
+    /// entry point, this waits on it directly and wakes up the instant a new
+    /// packet is published, with no ratelimiter and minimal latency. Older
+    /// DLLs that don't export the symbol fall back to the poll loop, which
+    /// returns the first packet it retrieves without checking that it is
+    /// newer than the last one (see [`try_next_flat`](Self::try_next_flat)).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if ten seconds pass without a new
+    /// packet being received. The assumption is that the game froze or
+    /// crashed, and waiting longer will not help.
+    pub fn next_flat(&mut self) -> Result<game::GameTickPacket, Box<dyn Error>> {
+        self.next_flat_with_timeout(Self::DEFAULT_TIMEOUT)
+    }
+
+    /// Block until the next game packet occurs, and then return it.
+    ///
+    /// This works the same as `next_flat`, but lets the caller choose the
+    /// timeout.
+    pub fn next_flat_with_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<game::GameTickPacket, Box<dyn Error>> {
+        if self.use_fresh_packets {
+            self.next_flat_blocking(timeout)
+        } else {
+            self.spin(|this| Ok(this.try_next_flat()), timeout)
+        }
+    }
+
+    /// Waits on the RLBot core DLL's blocking fresh-packet entry point
+    /// instead of polling, retrying until `timeout` elapses.
+    fn next_flat_blocking(&mut self, timeout: Duration) -> Result<game::GameTickPacket, Box<dyn Error>> {
+        let start = Instant::now();
+
+        loop {
+            let remaining = timeout.checked_sub(start.elapsed()).unwrap_or_default();
+            if let Some(packet) = self
+                .rlbot
+                .interface()
+                .fresh_live_data_packet_flatbuffer(remaining)
+            {
+                return Ok(packet);
+            }
+
+            if start.elapsed() > timeout {
+                return Err(From::from("no game packet received within the timeout"));
+            }
+        }
+    }
+
+    /// Polls for a new game packet.
+    ///
+    /// This is a stopgap until the game packet has a dedicated frame counter
+    /// to compare against like `RigidBodyTick` does; for now every
+    /// successfully retrieved packet is considered new.
+    pub fn try_next_flat(&mut self) -> Option<game::GameTickPacket> {
+        self.rlbot.interface().update_live_data_packet_flatbuffer()
+    }
+
+    /// Keep trying `f` until the timeout elapses.
+    fn spin<R>(
+        &mut self,
+        f: impl Fn(&mut Self) -> Result<Option<R>, Box<dyn Error>>,
+        timeout: Duration,
+    ) -> Result<R, Box<dyn Error>> {
+        let start = Instant::now();
+
+        loop {
+            self.ratelimiter.wait();
+
+            if let Some(packet) = f(self)? {
+                return Ok(packet);
+            }
+
+            let elapsed = Instant::now() - start;
+            if elapsed > timeout {
+                return Err(From::from("no game packet received within the timeout"));
+            }
+        }
+    }
+}
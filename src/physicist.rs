@@ -1,5 +1,6 @@
 use crate::{ffi, game, rlbot::RLBot};
 use std::{
+    collections::VecDeque,
     error::Error,
     mem::MaybeUninit,
     time::{Duration, Instant},
@@ -7,10 +8,20 @@ use std::{
 
 /// An iterator-like object that yields physics ticks from the game as they
 /// occur.
+///
+/// The background-thread APIs ([`into_stream`](r#async::PhysicistStream),
+/// [`into_mio_source`](mio_support::PhysicistSource)) are only implemented
+/// for `Physicist<'static>`: a spawned thread can't borrow a shorter-lived
+/// `RLBot`. In practice this isn't a limitation — `RLBot` instances are
+/// obtained once per process and kept around for its lifetime (the RLBot
+/// core DLL can't be safely unloaded mid-match), so every caller already
+/// holds a `Physicist<'static>` here.
 pub struct Physicist<'a> {
     rlbot: &'a RLBot,
     ratelimiter: ratelimit::Limiter,
     prev_ball_frame: i32,
+    use_fresh_packets: bool,
+    jitter: Option<JitterBuffer>,
 }
 
 impl<'a> Physicist<'a> {
@@ -27,6 +38,60 @@ impl<'a> Physicist<'a> {
             rlbot,
             ratelimiter,
             prev_ball_frame: 0,
+            use_fresh_packets: rlbot.interface().supports_fresh_packets(),
+            jitter: None,
+        }
+    }
+
+    /// Opts into a jitter buffer that detects and (depending on `mode`)
+    /// repairs dropped physics frames, instead of silently handing back
+    /// whatever `try_next_flat`/`next_flat` receive next.
+    ///
+    /// `capacity` bounds how many ticks the buffer keeps on hand to bridge a
+    /// gap; a few frames (a handful of milliseconds at 120Hz) is plenty.
+    pub fn with_jitter_buffer(mut self, capacity: usize, mode: JitterMode) -> Self {
+        self.jitter = Some(JitterBuffer::new(capacity, mode));
+        self
+    }
+
+    /// Gap and jitter statistics collected by the jitter buffer, if one was
+    /// enabled via [`with_jitter_buffer`](Self::with_jitter_buffer).
+    pub fn jitter_stats(&self) -> Option<JitterStats> {
+        self.jitter.as_ref().map(|j| j.stats)
+    }
+
+    /// Blocks until the next physics tick (real or, in [`JitterMode::Interpolated`],
+    /// synthesized to fill a detected gap) is available, and returns it.
+    ///
+    /// Requires a jitter buffer to have been enabled via
+    /// [`with_jitter_buffer`](Self::with_jitter_buffer).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if ten seconds pass without a new tick
+    /// being received, or if no jitter buffer is enabled.
+    pub fn next_buffered(&mut self) -> Result<BufferedTick, Box<dyn Error>> {
+        self.next_buffered_with_timeout(Self::DEFAULT_TIMEOUT)
+    }
+
+    /// Works the same as `next_buffered`, but lets the caller choose the
+    /// timeout.
+    pub fn next_buffered_with_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<BufferedTick, Box<dyn Error>> {
+        loop {
+            if let Some(buffered) = self.jitter.as_mut().and_then(|j| j.pop_pending()) {
+                return Ok(buffered);
+            }
+
+            let tick = self.next_flat_with_timeout(timeout)?;
+            let now = Instant::now();
+            let jitter = self
+                .jitter
+                .as_mut()
+                .ok_or("next_buffered requires a jitter buffer; call with_jitter_buffer first")?;
+            jitter.ingest(tick, now);
         }
     }
 
@@ -81,11 +146,49 @@ impl<'a> Physicist<'a> {
     ///
     /// This works the same as `next_flat`, but lets the caller choose the
     /// timeout.
+    ///
+    /// If the connected RLBot core DLL exports the blocking fresh-tick entry
+    /// point, this waits on it directly and wakes up the instant a new frame
+    /// is published, with no ratelimiter and minimal latency. Older DLLs that
+    /// don't export the symbol fall back to the poll loop.
     pub fn next_flat_with_timeout(
         &mut self,
         timeout: Duration,
     ) -> Result<game::RigidBodyTick, Box<dyn Error>> {
-        self.spin(|this| Ok(this.try_next_flat()), timeout)
+        if self.use_fresh_packets {
+            self.next_flat_blocking(timeout)
+        } else {
+            self.spin(|this| Ok(this.try_next_flat()), timeout)
+        }
+    }
+
+    /// Waits on the RLBot core DLL's blocking fresh-tick entry point instead
+    /// of polling, retrying until `timeout` elapses.
+    fn next_flat_blocking(&mut self, timeout: Duration) -> Result<game::RigidBodyTick, Box<dyn Error>> {
+        let start = Instant::now();
+
+        loop {
+            let remaining = timeout.checked_sub(start.elapsed()).unwrap_or_default();
+            if let Some(tick) = self
+                .rlbot
+                .interface()
+                .fresh_rigid_body_tick_flatbuffer(remaining)
+            {
+                if let Some(frame) = tick
+                    .ball
+                    .as_ref()
+                    .and_then(|b| b.state.as_ref())
+                    .map(|s| s.frame)
+                {
+                    self.prev_ball_frame = frame;
+                }
+                return Ok(tick);
+            }
+
+            if start.elapsed() > timeout {
+                return Err(From::from("no physics tick received within the timeout"));
+            }
+        }
     }
 
     /// Polls for a new physics tick.
@@ -133,3 +236,487 @@ impl<'a> Physicist<'a> {
         }
     }
 }
+
+/// How a [`Physicist`]'s jitter buffer should handle a detected gap (a ball
+/// frame number that jumped by more than one since the previous tick).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JitterMode {
+    /// Hand back only the ticks received (still reordered by frame number,
+    /// with duplicates dropped); gaps are counted in [`JitterStats`] but
+    /// nothing is synthesized.
+    GapReport,
+    /// Fill in missing frames with ticks synthesized by interpolating
+    /// between the two bracketing real ticks.
+    Interpolated,
+}
+
+/// Gap and jitter statistics collected by a [`Physicist`]'s jitter buffer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JitterStats {
+    /// Number of dropped physics frames detected so far.
+    pub gaps_detected: u64,
+    /// Number of ticks synthesized (interpolated) so far to fill detected
+    /// gaps, counted when they are queued rather than when they are handed
+    /// back.
+    pub synthesized_ticks: u64,
+    /// Running variance, in seconds squared, of the wall-clock time between
+    /// consecutive ticks arriving from the DLL. A healthy 120Hz stream has
+    /// variance close to zero; a rising value means ticks are arriving in
+    /// bursts rather than steadily.
+    pub jitter_variance: f64,
+}
+
+/// A single tick handed back by [`Physicist::next_buffered`], tagged with
+/// whether it was received from the game or synthesized to repair a gap.
+#[derive(Debug, Clone)]
+pub struct BufferedTick {
+    pub tick: game::RigidBodyTick,
+    /// `true` if this tick was interpolated to fill a detected gap rather
+    /// than received directly from RLBot.
+    pub synthesized: bool,
+}
+
+/// Smooths and repairs the 120Hz physics stream: reorders a small window of
+/// arrivals by frame number, detects dropped frames and, in
+/// [`JitterMode::Interpolated`] mode, synthesizes ticks to fill them in by
+/// interpolating between the bracketing real ticks.
+struct JitterBuffer {
+    mode: JitterMode,
+    capacity: usize,
+    /// Ticks held back, sorted ascending by ball frame number, so that a
+    /// tick arriving slightly out of order still gets released in the right
+    /// place. A tick is released (moved into `pending`) once the ring grows
+    /// past `capacity`.
+    ring: VecDeque<game::RigidBodyTick>,
+    pending: VecDeque<BufferedTick>,
+    prev_released_tick: Option<game::RigidBodyTick>,
+    prev_released_frame: Option<i32>,
+    last_arrival: Option<Instant>,
+    stats: JitterStats,
+    // Welford's online algorithm for inter-arrival time variance.
+    sample_count: u64,
+    mean_interval: f64,
+    m2: f64,
+}
+
+impl JitterBuffer {
+    fn new(capacity: usize, mode: JitterMode) -> Self {
+        Self {
+            mode,
+            capacity: capacity.max(1),
+            ring: VecDeque::new(),
+            pending: VecDeque::new(),
+            prev_released_tick: None,
+            prev_released_frame: None,
+            last_arrival: None,
+            stats: JitterStats::default(),
+            sample_count: 0,
+            mean_interval: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    fn frame_of(tick: &game::RigidBodyTick) -> Option<i32> {
+        tick.ball.as_ref().and_then(|b| b.state.as_ref()).map(|s| s.frame)
+    }
+
+    fn pop_pending(&mut self) -> Option<BufferedTick> {
+        self.pending.pop_front()
+    }
+
+    /// Records a freshly-received tick, updating jitter stats and inserting
+    /// it into the sorted ring. Once the ring exceeds `capacity`, the oldest
+    /// (lowest-frame) tick is released into `pending` for `next_buffered` to
+    /// hand back, with any detected gap before it repaired first.
+    fn ingest(&mut self, tick: game::RigidBodyTick, now: Instant) {
+        self.record_arrival(now);
+        self.insert_sorted(tick);
+
+        while self.ring.len() > self.capacity {
+            if let Some(tick) = self.ring.pop_front() {
+                self.release(tick);
+            }
+        }
+    }
+
+    /// Inserts `tick` into `ring`, keeping it sorted ascending by frame
+    /// number. Ticks without a readable frame number are appended at the
+    /// back rather than reordered; a duplicate of a frame already buffered
+    /// is dropped.
+    fn insert_sorted(&mut self, tick: game::RigidBodyTick) {
+        let frame = match Self::frame_of(&tick) {
+            Some(frame) => frame,
+            None => {
+                self.ring.push_back(tick);
+                return;
+            }
+        };
+
+        let mut index = self.ring.len();
+        for (i, buffered) in self.ring.iter().enumerate() {
+            match Self::frame_of(buffered) {
+                Some(buffered_frame) if buffered_frame == frame => return, // duplicate, drop it
+                Some(buffered_frame) if buffered_frame > frame => {
+                    index = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        self.ring.insert(index, tick);
+    }
+
+    /// Releases `tick`, filling in any gap detected since the last released
+    /// tick before handing it back.
+    fn release(&mut self, tick: game::RigidBodyTick) {
+        let frame = Self::frame_of(&tick);
+        let gap = match (self.prev_released_frame, frame) {
+            (Some(prev), Some(frame)) if frame > prev + 1 => Some((prev, frame)),
+            _ => None,
+        };
+
+        if let Some((prev_frame, frame)) = gap {
+            self.stats.gaps_detected += (frame - prev_frame - 1) as u64;
+
+            if self.mode == JitterMode::Interpolated {
+                if let Some(prev_tick) = self.prev_released_tick.clone() {
+                    let missing = (frame - prev_frame - 1) as usize;
+                    for i in 1..=missing {
+                        let t = i as f32 / (missing + 1) as f32;
+                        let synthesized = interpolate_tick(&prev_tick, &tick, t);
+                        self.stats.synthesized_ticks += 1;
+                        self.pending.push_back(BufferedTick {
+                            tick: synthesized,
+                            synthesized: true,
+                        });
+                    }
+                }
+            }
+        }
+
+        self.prev_released_frame = frame.or(self.prev_released_frame);
+        self.prev_released_tick = Some(tick.clone());
+        self.pending.push_back(BufferedTick {
+            tick,
+            synthesized: false,
+        });
+    }
+
+    fn record_arrival(&mut self, now: Instant) {
+        if let Some(last) = self.last_arrival {
+            let interval = now.duration_since(last).as_secs_f64();
+            self.sample_count += 1;
+            let (mean, m2, variance) =
+                welford_update(self.mean_interval, self.m2, self.sample_count, interval);
+            self.mean_interval = mean;
+            self.m2 = m2;
+            self.stats.jitter_variance = variance;
+        }
+        self.last_arrival = Some(now);
+    }
+}
+
+/// One step of Welford's online algorithm for mean/variance, given the
+/// running mean, running sum of squared differences (`m2`), the sample count
+/// *after* including `sample`, and the new sample itself. Returns the
+/// updated `(mean, m2, variance)`; variance is `0.0` until at least two
+/// samples have been seen.
+fn welford_update(mean: f64, m2: f64, count: u64, sample: f64) -> (f64, f64, f64) {
+    let delta = sample - mean;
+    let mean = mean + delta / count as f64;
+    let m2 = m2 + delta * (sample - mean);
+    let variance = if count > 1 { m2 / count as f64 } else { 0.0 };
+    (mean, m2, variance)
+}
+
+/// Linearly interpolates position/velocity and spherically interpolates
+/// (slerp) rotation for the ball and every car between two bracketing real
+/// ticks, at normalized time `t` in `(0, 1)`.
+fn interpolate_tick(a: &game::RigidBodyTick, b: &game::RigidBodyTick, t: f32) -> game::RigidBodyTick {
+    let mut out = a.clone();
+
+    if let (Some(ball_a), Some(ball_b)) = (a.ball.as_ref(), b.ball.as_ref()) {
+        if let Some(out_ball) = out.ball.as_mut() {
+            if let (Some(state_a), Some(state_b)) = (ball_a.state.as_ref(), ball_b.state.as_ref()) {
+                if let Some(out_state) = out_ball.state.as_mut() {
+                    *out_state = crate::interp::lerp_physics(state_a, state_b, t);
+                }
+            }
+        }
+    }
+
+    for ((car_a, car_b), out_car) in a
+        .players
+        .iter()
+        .zip(b.players.iter())
+        .zip(out.players.iter_mut())
+    {
+        if let (Some(state_a), Some(state_b)) = (car_a.state.as_ref(), car_b.state.as_ref()) {
+            if let Some(out_state) = out_car.state.as_mut() {
+                *out_state = crate::interp::lerp_physics(state_a, state_b, t);
+            }
+        }
+    }
+
+    out
+}
+
+/// An async adapter over [`Physicist`], for bots built on a `futures`/`tokio`
+/// runtime that would rather `.await` physics ticks than dedicate a thread to
+/// `loop { physicist.next_flat()? }`.
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::*;
+    use futures::channel::mpsc;
+    use futures::stream::Stream;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    impl Physicist<'static> {
+        /// Converts this [`Physicist`] into a [`Stream`] of physics ticks.
+        ///
+        /// The blocking poll loop runs on a dedicated background thread and
+        /// forwards each tick over a channel of the given `buffer` size, so
+        /// polling the stream never blocks the executor. Dropping the stream
+        /// signals the background thread to stop and joins it.
+        ///
+        /// See the [`Physicist`] struct docs for why this requires `'static`.
+        pub fn into_stream(self, buffer: usize) -> PhysicistStream {
+            // Poll for ticks in short slices so the loop re-checks `stop_rx`
+            // often; waiting a full `DEFAULT_TIMEOUT` between checks would
+            // make dropping the stream block for up to that long whenever
+            // ticks aren't flowing (e.g. the game is paused).
+            const POLL_TIMEOUT: Duration = Duration::from_millis(250);
+
+            let (mut sender, receiver) = mpsc::channel(buffer);
+            let (stop_tx, stop_rx) = std::sync::mpsc::channel();
+
+            let handle = std::thread::spawn(move || {
+                let mut physicist = self;
+                while stop_rx.try_recv().is_err() {
+                    let tick = match physicist.next_flat_with_timeout(POLL_TIMEOUT) {
+                        Ok(tick) => tick,
+                        Err(_) => continue,
+                    };
+                    // A momentarily slow consumer filling the bounded
+                    // channel must not kill the stream: drop this one tick
+                    // as backpressure and keep going. Only a disconnected
+                    // receiver (the stream was dropped) ends the thread.
+                    match sender.try_send(tick) {
+                        Ok(()) => {}
+                        Err(e) if e.is_disconnected() => break,
+                        Err(_) => {}
+                    }
+                }
+            });
+
+            PhysicistStream {
+                receiver,
+                stop: Some(stop_tx),
+                handle: Some(handle),
+            }
+        }
+    }
+
+    /// A [`Stream`] of [`game::RigidBodyTick`]s backed by a [`Physicist`]
+    /// running on a dedicated background thread.
+    ///
+    /// Created by [`Physicist::into_stream`]. Dropping this stream stops the
+    /// background thread.
+    pub struct PhysicistStream {
+        receiver: mpsc::Receiver<game::RigidBodyTick>,
+        stop: Option<std::sync::mpsc::Sender<()>>,
+        handle: Option<std::thread::JoinHandle<()>>,
+    }
+
+    impl Stream for PhysicistStream {
+        type Item = game::RigidBodyTick;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Pin::new(&mut self.receiver).poll_next(cx)
+        }
+    }
+
+    impl Drop for PhysicistStream {
+        fn drop(&mut self) {
+            if let Some(stop) = self.stop.take() {
+                let _ = stop.send(());
+            }
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+/// Lets a [`Physicist`] be driven from a `mio::Poll` event loop alongside
+/// sockets and timers, instead of dedicating a thread to the blocking poll
+/// loop.
+#[cfg(feature = "mio")]
+pub mod mio_support {
+    use super::*;
+    use mio::{Evented, Poll, PollOpt, Ready, Registration, SetReadiness, Token};
+    use std::io;
+    use std::sync::mpsc;
+
+    impl Physicist<'static> {
+        /// Spawns a background thread that watches for new physics ticks and
+        /// returns a [`PhysicistSource`] that can be registered with a
+        /// `mio::Poll` under a token.
+        ///
+        /// The watcher thread only uses frame numbers to decide when to
+        /// toggle readiness; it never hands a tick back to the caller
+        /// itself. Once `Poll::poll` reports the token readable, drain the
+        /// new tick by calling [`Physicist::try_next_flat`] on this same
+        /// `Physicist`, then call [`PhysicistSource::notify_drained`] so the
+        /// source goes back to not-readable until the next tick.
+        ///
+        /// When the connected RLBot core DLL exports the blocking
+        /// fresh-tick entry point, the watcher thread blocks on
+        /// `FreshRigidBodyTick` directly instead of running its own 1ms poll
+        /// loop, so this does not reintroduce a per-source spin thread on
+        /// DLLs new enough to support it. `FreshRigidBodyTick` maintains its
+        /// own "last seen" cursor, separate from the polling
+        /// `UpdateRigidBodyTickFlatbuffer` entry point `try_next_flat` reads
+        /// from, so the watcher calling it concurrently with the caller's
+        /// own reads is safe and doesn't consume the tick out from under
+        /// them.
+        ///
+        /// See the [`Physicist`] struct docs for why this requires `'static`.
+        pub fn into_mio_source(&self) -> PhysicistSource {
+            let (registration, set_readiness) = Registration::new2();
+            let (stop_tx, stop_rx) = mpsc::channel();
+            let rlbot = self.rlbot;
+            let use_fresh_packets = self.use_fresh_packets;
+
+            let handle = std::thread::spawn(move || {
+                let mut prev_frame = 0;
+                while stop_rx.try_recv().is_err() {
+                    let tick = if use_fresh_packets {
+                        rlbot
+                            .interface()
+                            .fresh_rigid_body_tick_flatbuffer(Duration::from_millis(250))
+                    } else {
+                        let tick = rlbot.interface().update_rigid_body_tick_flatbuffer();
+                        std::thread::sleep(Duration::from_millis(1));
+                        tick
+                    };
+
+                    if let Some(frame) = tick
+                        .as_ref()
+                        .and_then(|t| t.ball.as_ref())
+                        .and_then(|b| b.state.as_ref())
+                        .map(|s| s.frame)
+                    {
+                        if frame != prev_frame {
+                            prev_frame = frame;
+                            let _ = set_readiness.set_readiness(Ready::readable());
+                        }
+                    }
+                }
+            });
+
+            PhysicistSource {
+                registration,
+                set_readiness,
+                stop: stop_tx,
+                handle: Some(handle),
+            }
+        }
+    }
+
+    /// A `mio`-[`Evented`] source that becomes readable whenever a new
+    /// physics tick is published. Created by [`Physicist::into_mio_source`].
+    ///
+    /// This is level-triggered: once set readable, it stays readable until
+    /// [`notify_drained`](Self::notify_drained) is called, so callers must
+    /// call it after draining the tick via `try_next_flat` or `Poll::poll`
+    /// will keep returning immediately.
+    ///
+    /// Dropping this source stops its background watcher thread.
+    pub struct PhysicistSource {
+        registration: Registration,
+        set_readiness: SetReadiness,
+        stop: mpsc::Sender<()>,
+        handle: Option<std::thread::JoinHandle<()>>,
+    }
+
+    impl PhysicistSource {
+        /// Clears readiness after the caller has drained the new tick via
+        /// `try_next_flat`, so the source goes back to not-readable until
+        /// the watcher thread reports another one.
+        pub fn notify_drained(&self) {
+            let _ = self.set_readiness.set_readiness(Ready::empty());
+        }
+    }
+
+    impl Evented for PhysicistSource {
+        fn register(
+            &self,
+            poll: &Poll,
+            token: Token,
+            interest: Ready,
+            opts: PollOpt,
+        ) -> io::Result<()> {
+            poll.register(&self.registration, token, interest, opts)
+        }
+
+        fn reregister(
+            &self,
+            poll: &Poll,
+            token: Token,
+            interest: Ready,
+            opts: PollOpt,
+        ) -> io::Result<()> {
+            poll.reregister(&self.registration, token, interest, opts)
+        }
+
+        fn deregister(&self, poll: &Poll) -> io::Result<()> {
+            poll.deregister(&self.registration)
+        }
+    }
+
+    impl Drop for PhysicistSource {
+        fn drop(&mut self) {
+            let _ = self.stop.send(());
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::welford_update;
+
+    #[test]
+    fn welford_update_reports_zero_variance_for_a_single_sample() {
+        let (_, _, variance) = welford_update(0.0, 0.0, 1, 1.0 / 120.0);
+        assert_eq!(variance, 0.0);
+    }
+
+    #[test]
+    fn welford_update_tracks_a_running_mean_across_many_samples() {
+        let (mut mean, mut m2) = (0.0, 0.0);
+        for i in 1..=120u64 {
+            let (new_mean, new_m2, _) = welford_update(mean, m2, i, 1.0 / 120.0);
+            mean = new_mean;
+            m2 = new_m2;
+        }
+        assert!((mean - 1.0 / 120.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn welford_update_reports_nonzero_variance_for_jittery_intervals() {
+        let samples = [1.0 / 120.0, 1.0 / 60.0, 1.0 / 120.0, 1.0 / 30.0];
+        let (mut mean, mut m2, mut variance) = (0.0, 0.0, 0.0);
+        for (i, &sample) in samples.iter().enumerate() {
+            let result = welford_update(mean, m2, i as u64 + 1, sample);
+            mean = result.0;
+            m2 = result.1;
+            variance = result.2;
+        }
+        assert!(variance > 0.0);
+    }
+}